@@ -1,12 +1,102 @@
 //! Rocket's logging infrastructure.
 
 use std::str::FromStr;
+use std::collections::HashMap;
 use std::fmt;
+use std::env;
 
-use log::{self, Log, LogLevel, LogRecord, LogMetadata};
-use yansi::Paint;
+use std::io::{self, Write};
+use std::time::SystemTime;
 
-struct RocketLogger(LoggingLevel);
+use log::{self, Log, LogLevel, LogLevelFilter, LogRecord, LogMetadata};
+use regex::Regex;
+use termcolor::{self, Color, ColorSpec, StandardStream, WriteColor};
+
+/// The name of the environment variable consulted for per-module log
+/// directives, in the style of `env_logger`'s `RUST_LOG`. For example:
+/// `ROCKET_LOG=info,hyper=off,myapp::db=debug`.
+const ROCKET_LOG_ENV: &'static str = "ROCKET_LOG";
+
+/// A single `target=level[/regex]` directive parsed out of `ROCKET_LOG`.
+struct Directive {
+    /// The module path prefix this directive applies to. The empty string
+    /// matches every module path.
+    path: String,
+    /// The level enabled for `path` and everything nested beneath it.
+    level: LogLevelFilter,
+    /// An optional filter applied to the formatted message text.
+    filter: Option<Regex>,
+}
+
+/// Parses a `ROCKET_LOG`-style directive string into a list of `Directive`s.
+/// Directives are comma-separated; each is either a bare level (applying to
+/// every module) or `path=level`, optionally suffixed with `/regex` to also
+/// filter on the formatted message. Empty or malformed entries are skipped.
+fn parse_directives(spec: &str) -> Vec<Directive> {
+    spec.split(',')
+        .filter_map(|entry| {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                return None;
+            }
+
+            let (target_level, filter) = match entry.find('/') {
+                Some(i) => {
+                    match Regex::new(&entry[(i + 1)..]) {
+                        Ok(filter) => (&entry[..i], Some(filter)),
+                        // A filter that fails to compile invalidates the
+                        // whole directive; don't silently match everything.
+                        Err(_) => return None
+                    }
+                }
+                None => (entry, None)
+            };
+
+            let (path, level_str) = match target_level.find('=') {
+                Some(i) => (&target_level[..i], &target_level[(i + 1)..]),
+                None => ("", target_level)
+            };
+
+            match level_str.parse::<LogLevelFilter>() {
+                Ok(level) => Some(Directive { path: path.into(), level: level, filter: filter }),
+                Err(_) => None
+            }
+        })
+        .collect()
+}
+
+struct RocketLogger {
+    level: LoggingLevel,
+    /// Directives controlling per-module filtering, sorted by descending
+    /// path length so the longest (most specific) match wins.
+    directives: Vec<Directive>,
+    color: ColorChoice,
+    timestamps: Timestamps,
+    /// When `true`, disables routing `Error`/`Warn` records to stderr; this
+    /// is a compatibility switch for consumers that expect all of Rocket's
+    /// log output to come from a single stream.
+    all_stdout: bool,
+}
+
+/// Writes `args` to `out` in `color`, bolded if `bold`, then resets styling.
+fn write_colored(out: &mut WriteColor, color: Color, bold: bool, args: fmt::Arguments) -> io::Result<()> {
+    out.set_color(ColorSpec::new().set_fg(Some(color)).set_bold(bold))?;
+    write!(out, "{}", args)?;
+    out.reset()
+}
+
+/// Like `write_colored`, but follows with a newline (written uncolored).
+fn writeln_colored(out: &mut WriteColor, color: Color, bold: bool, args: fmt::Arguments) -> io::Result<()> {
+    write_colored(out, color, bold, args)?;
+    writeln!(out)
+}
+
+/// Writes `stamp` dimmed, followed by a space, with no trailing newline.
+fn write_timestamp(out: &mut WriteColor, stamp: &str) -> io::Result<()> {
+    out.set_color(ColorSpec::new().set_dimmed(true))?;
+    write!(out, "{} ", stamp)?;
+    out.reset()
+}
 
 /// Defines the different levels for log messages.
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
@@ -57,6 +147,72 @@ impl fmt::Display for LoggingLevel {
     }
 }
 
+/// Whether and when Rocket colors its log output.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ColorChoice {
+    /// Color when the stream being written to (stdout or stderr) is a
+    /// terminal; this is the default.
+    Auto,
+    /// Always color, even when standard output is redirected to a file or
+    /// piped to another program.
+    Always,
+    /// Never color, regardless of whether standard output is a terminal.
+    Never,
+}
+
+impl ColorChoice {
+    fn to_termcolor(&self) -> termcolor::ColorChoice {
+        match *self {
+            ColorChoice::Auto => termcolor::ColorChoice::Auto,
+            ColorChoice::Always => termcolor::ColorChoice::Always,
+            ColorChoice::Never => termcolor::ColorChoice::Never,
+        }
+    }
+}
+
+/// Controls whether and how a timestamp is prefixed to each emitted line.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Timestamps {
+    /// No timestamp is printed. This is the default.
+    Off,
+    /// A compact `HH:MM:SS` timestamp.
+    Seconds,
+    /// A compact `HH:MM:SS.mmm` timestamp, with millisecond precision.
+    Millis,
+    /// A full RFC 3339 timestamp, e.g. `2020-01-01T12:34:56.789123456Z`.
+    Rfc3339,
+}
+
+/// Formats the current wall-clock time according to `timestamps`, or returns
+/// `None` when timestamps are off. `Seconds`/`Millis` are derived from the
+/// same RFC 3339 instant so all three modes agree on the current second.
+fn format_timestamp(timestamps: Timestamps) -> Option<String> {
+    if timestamps == Timestamps::Off {
+        return None;
+    }
+
+    let rfc3339 = humantime::format_rfc3339(SystemTime::now()).to_string();
+    if timestamps == Timestamps::Rfc3339 {
+        return Some(rfc3339);
+    }
+
+    // `rfc3339` looks like "2020-01-01T12:34:56.789123456Z"; pull out the
+    // `HH:MM:SS[.nnnnnnnnn]` portion and trim it to the precision we want.
+    let time_part = rfc3339.splitn(2, 'T').nth(1).unwrap_or(&rfc3339);
+    let time_part = time_part.trim_end_matches('Z');
+    let mut pieces = time_part.splitn(2, '.');
+    let hms = pieces.next().unwrap_or("00:00:00");
+
+    Some(match timestamps {
+        Timestamps::Millis => {
+            let nanos = pieces.next().unwrap_or("000000000");
+            let millis = if nanos.len() >= 3 { &nanos[..3] } else { "000" };
+            format!("{}.{}", hms, millis)
+        }
+        _ => hms.to_string(),
+    })
+}
+
 #[doc(hidden)] #[macro_export]
 macro_rules! log_ {
     ($name:ident: $format:expr) => { log_!($name: $format,) };
@@ -83,18 +239,98 @@ macro_rules! debug_ { ($($args:expr),+) => { log_!(debug: $($args),+); }; }
 #[doc(hidden)] #[macro_export]
 macro_rules! warn_ { ($($args:expr),+) => { log_!(warn: $($args),+); }; }
 
+impl RocketLogger {
+    fn new(level: LoggingLevel, color: ColorChoice, timestamps: Timestamps, all_stdout: bool) -> RocketLogger {
+        let mut directives: HashMap<String, Directive> = HashMap::new();
+
+        // By default, silence the noisy internals of our dependencies unless
+        // the user asked for full debug output. A `ROCKET_LOG` directive for
+        // the same path (below) takes precedence over this default.
+        if level != LoggingLevel::Debug {
+            let off = LogLevelFilter::Off;
+            directives.insert("hyper::".into(), Directive { path: "hyper::".into(), level: off, filter: None });
+            directives.insert("rustls::".into(), Directive { path: "rustls::".into(), level: off, filter: None });
+        }
+
+        if let Ok(spec) = env::var(ROCKET_LOG_ENV) {
+            for directive in parse_directives(&spec) {
+                directives.insert(directive.path.clone(), directive);
+            }
+        }
+
+        let mut directives: Vec<Directive> = directives.into_iter().map(|(_, d)| d).collect();
+        directives.sort_by(|a, b| b.path.len().cmp(&a.path.len()));
+
+        RocketLogger {
+            level: level,
+            directives: directives,
+            color: color,
+            timestamps: timestamps,
+            all_stdout: all_stdout,
+        }
+    }
+
+    /// Returns a fresh handle to the stream `level` should be written to:
+    /// stderr for `Error`/`Warn` (unless `all_stdout` is set), stdout
+    /// otherwise. Each stream does its own TTY/color detection, so a pipe
+    /// on one doesn't suppress colors on the other.
+    fn stream_for(&self, level: LogLevel) -> StandardStream {
+        let to_stderr = !self.all_stdout && (level == LogLevel::Error || level == LogLevel::Warn);
+        let is_tty = if to_stderr { ::isatty::stderr_isatty() } else { ::isatty::stdout_isatty() };
+        let choice = match self.color {
+            ColorChoice::Auto if !is_tty => termcolor::ColorChoice::Never,
+            color => color.to_termcolor(),
+        };
+
+        if to_stderr {
+            StandardStream::stderr(choice)
+        } else {
+            StandardStream::stdout(choice)
+        }
+    }
+
+    /// Returns the level and optional message filter that apply to
+    /// `module_path`, using the longest matching directive or falling back
+    /// to this logger's default level when nothing matches.
+    fn directive_for(&self, module_path: &str) -> (LogLevelFilter, Option<&Regex>) {
+        for directive in &self.directives {
+            if module_path.starts_with(directive.path.as_str()) {
+                return (directive.level, directive.filter.as_ref());
+            }
+        }
+
+        (self.level.max_log_level().to_log_level_filter(), None)
+    }
+
+    /// The most permissive level enabled by this logger: the louder of the
+    /// global `level` and any `ROCKET_LOG` directive. This is what must be
+    /// installed as `log`'s global filter, since the `log` macros check it
+    /// *before* ever calling `enabled`/`log` and would otherwise silently
+    /// drop records that a directive meant to let through.
+    fn max_level_filter(&self) -> LogLevelFilter {
+        let default = self.level.max_log_level().to_log_level_filter();
+        self.directives.iter()
+            .map(|directive| directive.level)
+            .fold(default, |max, level| if level > max { level } else { max })
+    }
+}
+
 impl Log for RocketLogger {
     #[inline(always)]
     fn enabled(&self, md: &LogMetadata) -> bool {
-        md.level() <= self.0.max_log_level()
+        let (level, _) = self.directive_for(md.target());
+        md.level() <= level
     }
 
     fn log(&self, record: &LogRecord) {
-        // Print nothing if this level isn't enabled.
-        if !self.enabled(record.metadata()) {
-            return;
-        }
-
+        // Note: we deliberately don't gate on `self.enabled(record.metadata())`
+        // here. Every one of Rocket's own logging macros hard-codes `target`
+        // to `"_"` or `"launch"` (never a real module path), so `enabled()`
+        // can only ever resolve the fallback, global-`level` directive for
+        // them; the module-path-based check below, using the record's real
+        // `location().module_path()`, is the one that's actually correct and
+        // must be the sole gate.
+        //
         // We use the `launch_info` macro to "fake" a high priority info
         // message. We want to print the message unless the user uses a custom
         // drain, so we set it's status to critical, but reset it here to info.
@@ -103,111 +339,254 @@ impl Log for RocketLogger {
             _ => record.level()
         };
 
-        // Don't print Hyper or Rustls messages unless debug is enabled.
-        let from_hyper = record.location().module_path().starts_with("hyper::");
-        let from_rustls = record.location().module_path().starts_with("rustls::");
-        if self.0 != LoggingLevel::Debug && (from_hyper || from_rustls) {
+        // Walk the per-module directives (`ROCKET_LOG`) to find the level
+        // and optional message filter for where this record came from; this
+        // is also how Hyper/Rustls noise is muted by default.
+        let (directive_level, filter) = self.directive_for(record.location().module_path());
+        if record.level() > directive_level {
             return;
         }
 
+        if let Some(filter) = filter {
+            if !filter.is_match(&record.args().to_string()) {
+                return;
+            }
+        }
+
+        // Each record gets a fresh handle; `stream_for` checks whether the
+        // chosen stream is a terminal (for `Auto`) and termcolor picks the
+        // right ANSI/Win32-console path, so we don't have to.
+        let mut out = self.stream_for(level);
+
+        if let Some(stamp) = format_timestamp(self.timestamps) {
+            let _ = write_timestamp(&mut out, &stamp);
+        }
+
         // In Rocket, we abuse target with value "_" to indicate indentation.
-        if record.target() == "_" && self.0 != LoggingLevel::Critical {
-            print!("    {} ", Paint::white("=>"));
+        if record.target() == "_" && self.level != LoggingLevel::Critical {
+            let _ = write_colored(&mut out, Color::White, false, format_args!("    => "));
         }
 
         use log::LogLevel::*;
-        match level {
-            Info => println!("{}", Paint::blue(record.args())),
-            Trace => println!("{}", Paint::purple(record.args())),
+        let _ = match level {
+            Info => writeln_colored(&mut out, Color::Blue, false, *record.args()),
+            Trace => writeln_colored(&mut out, Color::Magenta, false, *record.args()),
             Error => {
-                println!("{} {}",
-                         Paint::red("Error:").bold(),
-                         Paint::red(record.args()))
+                write_colored(&mut out, Color::Red, true, format_args!("Error: "))
+                    .and_then(|_| writeln_colored(&mut out, Color::Red, false, *record.args()))
             }
             Warn => {
-                println!("{} {}",
-                         Paint::yellow("Warning:").bold(),
-                         Paint::yellow(record.args()))
+                write_colored(&mut out, Color::Yellow, true, format_args!("Warning: "))
+                    .and_then(|_| writeln_colored(&mut out, Color::Yellow, false, *record.args()))
             }
             Debug => {
                 let loc = record.location();
-                print!("\n{} ", Paint::blue("-->").bold());
-                println!("{}:{}", Paint::blue(loc.file()), Paint::blue(loc.line()));
-                println!("{}", record.args());
+                writeln!(out)
+                    .and_then(|_| write_colored(&mut out, Color::Blue, true, format_args!("--> ")))
+                    .and_then(|_| writeln_colored(&mut out, Color::Blue, false,
+                                                   format_args!("{}:{}", loc.file(), loc.line())))
+                    .and_then(|_| writeln!(out, "{}", record.args()))
             }
+        };
+    }
+}
+
+/// Installs `log` as the global logger, with the max log level derived from
+/// `level`.
+///
+/// This is the hook for applications that want Rocket's launch and request
+/// messages to go somewhere other than Rocket's own colored terminal output:
+/// a file, syslog, or a log aggregator. The installed `log` replaces Rocket's
+/// built-in logger; that logger is not public, so `log` cannot forward
+/// records to it. Two conventions of Rocket's own records are worth knowing
+/// when writing such a `log::Log`:
+///
+///   * Records with target `"_"` are continuation lines that belong to the
+///     previous, non-`"_"`-targeted record; Rocket's own drain indents them.
+///   * Records with target `"launch"` are high-priority informational
+///     messages emitted at startup; they're logged at `Error` level so they
+///     aren't accidentally filtered out, but should be treated as `Info`.
+///
+/// Returns the error from `log::set_logger` if a logger has already been
+/// installed.
+pub fn try_init_with<L: Log + 'static>(level: LoggingLevel, log: L) -> Result<(), log::SetLoggerError> {
+    log::set_logger(|max_log_level| {
+        max_log_level.set(level.max_log_level().to_log_level_filter());
+        Box::new(log)
+    })
+}
+
+#[doc(hidden)]
+pub fn try_init(
+    level: LoggingLevel,
+    verbose: bool,
+    color: ColorChoice,
+    timestamps: Timestamps,
+    all_stdout: bool
+) {
+    // We can't go through `try_init_with` here: it sets `log`'s global
+    // filter purely from `level`, but `ROCKET_LOG` directives can ask for
+    // more verbosity than `level` allows for a given module, and that
+    // filter is checked by the `log` macros before `RocketLogger` is ever
+    // consulted. Install the logger's own, directive-aware max level instead.
+    let logger = RocketLogger::new(level, color, timestamps, all_stdout);
+    let max_level = logger.max_level_filter();
+    let result = log::set_logger(|max_log_level| {
+        max_log_level.set(max_level);
+        Box::new(logger)
+    });
+
+    if let Err(err) = result {
+        if verbose {
+            println!("Logger failed to initialize: {}", err);
         }
     }
 }
 
-#[cfg(windows)]
-mod windows_console {
-    use std::os::raw::c_void;
+#[doc(hidden)]
+pub fn init(level: LoggingLevel) {
+    try_init(level, true, ColorChoice::Auto, Timestamps::Off, false)
+}
 
-    #[allow(non_camel_case_types)] type c_ulong = u32;
-    #[allow(non_camel_case_types)] type c_int = i32;
-    type DWORD = c_ulong;
-    type LPDWORD = *mut DWORD;
-    type HANDLE = *mut c_void;
-    type BOOL = c_int;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: DWORD = 0x0004;
-    const STD_OUTPUT_HANDLE: DWORD = 0xFFFFFFF5;
-    const INVALID_HANDLE_VALUE: HANDLE = -1isize as HANDLE;
-    const FALSE: BOOL = 0;
-    const TRUE: BOOL = 1;
+    fn logger_with_directives(level: LoggingLevel, mut directives: Vec<Directive>) -> RocketLogger {
+        directives.sort_by(|a, b| b.path.len().cmp(&a.path.len()));
+        RocketLogger {
+            level: level,
+            directives: directives,
+            color: ColorChoice::Never,
+            timestamps: Timestamps::Off,
+            all_stdout: false,
+        }
+    }
 
-    // This is the win32 console API, taken from the 'winapi' crate.
-    extern "system" {
-        fn GetStdHandle(nStdHandle: DWORD) -> HANDLE;
-        fn GetConsoleMode(hConsoleHandle: HANDLE, lpMode: LPDWORD) -> BOOL;
-        fn SetConsoleMode(hConsoleHandle: HANDLE, dwMode: DWORD) -> BOOL;
+    #[test]
+    fn parses_bare_level_as_catch_all() {
+        let directives = parse_directives("debug");
+        assert_eq!(directives.len(), 1);
+        assert_eq!(directives[0].path, "");
+        assert_eq!(directives[0].level, LogLevelFilter::Debug);
+        assert!(directives[0].filter.is_none());
     }
 
-    pub fn enable_ascii_colors() -> bool {
-        unsafe {
-            let stdout_handle: HANDLE = GetStdHandle(STD_OUTPUT_HANDLE);
-            if stdout_handle == INVALID_HANDLE_VALUE {
-                return false
-            }
+    #[test]
+    fn parses_path_level_directive() {
+        let directives = parse_directives("hyper=off");
+        assert_eq!(directives.len(), 1);
+        assert_eq!(directives[0].path, "hyper");
+        assert_eq!(directives[0].level, LogLevelFilter::Off);
+        assert!(directives[0].filter.is_none());
+    }
 
-            let mut dw_mode: DWORD = 0;
-            if GetConsoleMode(stdout_handle, &mut dw_mode) == FALSE {
-                return false
-            }
+    #[test]
+    fn parses_path_level_regex_directive() {
+        let directives = parse_directives("myapp::db=debug/slow query");
+        assert_eq!(directives.len(), 1);
+        assert_eq!(directives[0].path, "myapp::db");
+        assert_eq!(directives[0].level, LogLevelFilter::Debug);
 
-            dw_mode |= ENABLE_VIRTUAL_TERMINAL_PROCESSING;
-            SetConsoleMode(stdout_handle, dw_mode) == TRUE
-        }
+        let filter = directives[0].filter.as_ref().expect("regex filter");
+        assert!(filter.is_match("a slow query happened"));
+        assert!(!filter.is_match("nothing to see here"));
     }
-}
 
-#[cfg(not(windows))]
-mod windows_console {
-    pub fn enable_ascii_colors() -> bool { true }
-}
+    #[test]
+    fn multiple_comma_separated_directives_all_parse() {
+        let directives = parse_directives("info,hyper=off,myapp::db=debug");
+        assert_eq!(directives.len(), 3);
+    }
 
-#[doc(hidden)]
-pub fn try_init(level: LoggingLevel, verbose: bool) {
-    if !::isatty::stdout_isatty() {
-        Paint::disable();
-    } else if cfg!(windows) {
-        // TODO: Should we disable colors on Windows if this doesn't succeed?
-        windows_console::enable_ascii_colors();
+    #[test]
+    fn skips_malformed_entries_instead_of_aborting() {
+        // Unknown level: the whole entry is dropped.
+        assert!(parse_directives("myapp::db=not-a-level").is_empty());
+
+        // Regex fails to compile: the whole entry is dropped, not
+        // downgraded to a directive with no filter (i.e. "match everything").
+        assert!(parse_directives("myapp::db=debug/(").is_empty());
+
+        // Empty entries between/around commas are skipped, not malformed.
+        let directives = parse_directives(",,info,,");
+        assert_eq!(directives.len(), 1);
+        assert_eq!(directives[0].level, LogLevelFilter::Info);
     }
 
-    let result = log::set_logger(|max_log_level| {
-        max_log_level.set(level.max_log_level().to_log_level_filter());
-        Box::new(RocketLogger(level))
-    });
+    #[test]
+    fn directive_for_prefers_the_longest_matching_prefix() {
+        let logger = logger_with_directives(
+            LoggingLevel::Normal,
+            parse_directives("myapp=debug,myapp::db=trace")
+        );
 
-    if let Err(err) = result {
-        if verbose {
-            println!("Logger failed to initialize: {}", err);
-        }
+        let (level, _) = logger.directive_for("myapp::db::pool");
+        assert_eq!(level, LogLevelFilter::Trace);
+
+        let (level, _) = logger.directive_for("myapp::http");
+        assert_eq!(level, LogLevelFilter::Debug);
+
+        // No directive matches; falls back to the logger's global level.
+        let (level, _) = logger.directive_for("hyper::client");
+        assert_eq!(level, LoggingLevel::Normal.max_log_level().to_log_level_filter());
     }
-}
 
-#[doc(hidden)]
-pub fn init(level: LoggingLevel) {
-    try_init(level, true)
+    #[test]
+    fn default_directives_mute_hyper_and_rustls_by_module_prefix_only() {
+        let logger = RocketLogger::new(LoggingLevel::Normal, ColorChoice::Never, Timestamps::Off, false);
+
+        let (level, _) = logger.directive_for("hyper::client");
+        assert_eq!(level, LogLevelFilter::Off);
+
+        let (level, _) = logger.directive_for("rustls::session");
+        assert_eq!(level, LogLevelFilter::Off);
+
+        // Crates that merely start with "hyper"/"rustls" aren't muted.
+        let normal_level = LoggingLevel::Normal.max_log_level().to_log_level_filter();
+        let (level, _) = logger.directive_for("hypervisor::log");
+        assert_eq!(level, normal_level);
+        let (level, _) = logger.directive_for("rustlsx::log");
+        assert_eq!(level, normal_level);
+    }
+
+    #[test]
+    fn debug_level_disables_the_default_hyper_rustls_mute() {
+        let logger = RocketLogger::new(LoggingLevel::Debug, ColorChoice::Never, Timestamps::Off, false);
+        let (level, _) = logger.directive_for("hyper::client");
+        assert_eq!(level, LogLevelFilter::Trace);
+    }
+
+    #[test]
+    fn max_level_filter_is_the_loudest_of_global_level_and_directives() {
+        let logger = logger_with_directives(
+            LoggingLevel::Critical,
+            parse_directives("rocket::config=debug")
+        );
+
+        // The directive asks for far more verbosity than the global level.
+        assert_eq!(logger.max_level_filter(), LogLevelFilter::Debug);
+    }
+
+    #[test]
+    fn enabled_and_log_resolve_different_directives_for_rocket_s_own_macros() {
+        // Every Rocket logging macro hard-codes `target` to "_" or "launch",
+        // never a real module path, so resolving a directive against
+        // `target()` (what `enabled()` does) can only ever see the
+        // fallback, global-level directive -- never a module-specific
+        // escalation like the one resolved against the real
+        // `location().module_path()` (what `log()` does). This is why
+        // `log()` must not gate on `enabled()`.
+        let logger = logger_with_directives(
+            LoggingLevel::Critical,
+            parse_directives("rocket::config=debug")
+        );
+
+        let (target_level, _) = logger.directive_for("_");
+        let (module_level, _) = logger.directive_for("rocket::config");
+
+        assert_eq!(target_level, LogLevelFilter::Warn);
+        assert_eq!(module_level, LogLevelFilter::Debug);
+        assert!(target_level < module_level);
+    }
 }